@@ -0,0 +1,12 @@
+#![no_main]
+use arbitrary::Unstructured;
+use libfuzzer_sys::fuzz_target;
+use npl_parser::arbitrary_source; // requires npl_parser's `arbitrary` feature
+
+fuzz_target!(|data: &[u8]| {
+    let mut u = Unstructured::new(data);
+    if let Ok(source) = arbitrary_source(&mut u) {
+        npl_parser::parse(&source)
+            .unwrap_or_else(|e| panic!("generated program failed to parse: {e}\n{source}"));
+    }
+});
@@ -0,0 +1,158 @@
+//! Typed-input fuzzing support: a small `arbitrary`-derived AST for NPL,
+//! independent of the lossless CST in `syntax.rs`, plus a printer that turns
+//! it into source text. Only compiled behind the `arbitrary` feature so the
+//! derives don't leak into normal builds (mirrors the pattern the `itoa`
+//! fuzzer uses to drive its target with well-typed values instead of raw
+//! bytes).
+
+use arbitrary::{Arbitrary, Unstructured};
+
+/// A bare NPL identifier. `arbitrary`'s `String` impl can produce characters
+/// the lexer doesn't accept as identifier text, so this picks from a fixed,
+/// lexer-legal alphabet instead.
+#[derive(Debug, Clone)]
+pub struct Ident(pub String);
+
+impl<'a> Arbitrary<'a> for Ident {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz_";
+        loop {
+            let len = (u.arbitrary::<u8>()? % 8) as usize + 1;
+            let mut s = String::with_capacity(len);
+            for _ in 0..len {
+                let idx = (u.arbitrary::<u8>()? as usize) % ALPHABET.len();
+                s.push(ALPHABET[idx] as char);
+            }
+            // Keywords lex as their own token, not `Ident` — re-roll rather
+            // than hand the printer an identifier the parser won't accept.
+            if !crate::lexer::is_keyword(&s) {
+                return Ok(Ident(s));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Arbitrary)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BinOp {
+    fn as_str(&self) -> &'static str {
+        match self {
+            BinOp::Add => "+",
+            BinOp::Sub => "-",
+            BinOp::Mul => "*",
+            BinOp::Div => "/",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Arbitrary)]
+pub enum Expr {
+    Number(u32),
+    Name(Ident),
+    Paren(Box<Expr>),
+    Bin(Box<Expr>, BinOp, Box<Expr>),
+    Call(Ident, Vec<Expr>),
+}
+
+impl Expr {
+    fn write_operand(&self, out: &mut String) {
+        if matches!(self, Expr::Bin(..)) {
+            out.push('(');
+            self.write(out);
+            out.push(')');
+        } else {
+            self.write(out);
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        match self {
+            Expr::Number(n) => out.push_str(&n.to_string()),
+            Expr::Name(id) => out.push_str(&id.0),
+            Expr::Paren(inner) => {
+                out.push('(');
+                inner.write(out);
+                out.push(')');
+            }
+            Expr::Bin(lhs, op, rhs) => {
+                // Always parenthesize a nested `Bin` operand: the printed
+                // form has to reparse to the same tree shape regardless of
+                // which operators ended up nested inside which, and the
+                // parser's own precedence would otherwise re-associate
+                // `Bin(Bin(a, _, b), Mul, c)` away from how it was generated.
+                Self::write_operand(lhs, out);
+                out.push(' ');
+                out.push_str(op.as_str());
+                out.push(' ');
+                Self::write_operand(rhs, out);
+            }
+            Expr::Call(name, args) => {
+                out.push_str(&name.0);
+                out.push('(');
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    arg.write(out);
+                }
+                out.push(')');
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Arbitrary)]
+pub enum Stmt {
+    Let(Ident, Expr),
+    Expr(Expr),
+}
+
+impl Stmt {
+    fn write(&self, out: &mut String) {
+        match self {
+            Stmt::Let(name, value) => {
+                out.push_str("let ");
+                out.push_str(&name.0);
+                out.push_str(" = ");
+                value.write(out);
+                out.push(';');
+            }
+            Stmt::Expr(expr) => {
+                expr.write(out);
+                out.push(';');
+            }
+        }
+    }
+}
+
+/// A well-typed NPL program, generated from fuzzer bytes via `arbitrary`.
+#[derive(Debug, Clone, Arbitrary)]
+pub struct Program(pub Vec<Stmt>);
+
+impl Program {
+    /// Prints the generated AST to NPL source text for feeding through
+    /// [`crate::parse`] or [`crate::parse_recovering`].
+    pub fn to_source(&self) -> String {
+        let mut out = String::new();
+        for stmt in &self.0 {
+            stmt.write(&mut out);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Consumes fuzzer bytes, generates a syntactically plausible `Program`, and
+/// prints it to source. Every string this returns is expected to satisfy the
+/// stronger fuzzing property a `Program` gives you for free: it re-parses to
+/// an equivalent AST, rather than merely failing to panic the parser.
+pub fn arbitrary_source(u: &mut Unstructured<'_>) -> arbitrary::Result<String> {
+    let program = Program::arbitrary(u)?;
+    Ok(program.to_source())
+}
@@ -0,0 +1,11 @@
+//! Emits a libFuzzer dictionary of NPL keywords/operators to stdout.
+//!
+//! Usage, from `npl_parser/`: `cargo run --bin gen_dict > ../fuzz/npl.dict`,
+//! then point libFuzzer at it with `-dict=npl.dict` (run from `fuzz/`) so
+//! splicing favors real tokens.
+
+use npl_parser::dict::{write_libfuzzer_dict, KEYWORD_AND_OPERATOR_LITERALS};
+
+fn main() -> std::io::Result<()> {
+    write_libfuzzer_dict(std::io::stdout().lock(), KEYWORD_AND_OPERATOR_LITERALS)
+}
@@ -0,0 +1,19 @@
+use std::io::{self, Write};
+
+/// Every keyword, punctuation, and operator literal the lexer recognizes.
+/// Used to generate a libFuzzer dictionary so coverage-guided fuzzing can
+/// splice meaningful tokens instead of discovering them byte-by-byte.
+pub const KEYWORD_AND_OPERATOR_LITERALS: &[&str] = &[
+    "let", "fn", "if", "else", "return", "+", "-", "*", "/", "==", "!=", "=", "!", "<", ">", "(",
+    ")", "{", "}", ",", ";",
+];
+
+/// Writes `literals` as a libFuzzer/AFL `.dict` file: one `"token"` or
+/// `name="token"` entry per line, per the format documented in the Android
+/// `rust_fuzz` docs.
+pub fn write_libfuzzer_dict<W: Write>(mut w: W, literals: &[&str]) -> io::Result<()> {
+    for (i, lit) in literals.iter().enumerate() {
+        writeln!(w, "tok{}=\"{}\"", i, lit.replace('\\', "\\\\").replace('"', "\\\""))?;
+    }
+    Ok(())
+}
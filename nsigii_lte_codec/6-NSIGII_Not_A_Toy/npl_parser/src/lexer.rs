@@ -0,0 +1,173 @@
+use crate::error::Span;
+
+/// Every terminal the NPL lexer can produce, including trivia (whitespace,
+/// comments) so that a token stream can reconstruct the source byte-for-byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Whitespace,
+    Comment,
+    Ident,
+    Number,
+    String,
+    KwLet,
+    KwFn,
+    KwIf,
+    KwElse,
+    KwReturn,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Eq,
+    EqEq,
+    Bang,
+    BangEq,
+    Lt,
+    Gt,
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    Comma,
+    Semi,
+    Error,
+    /// Never yielded by [`tokenize`]: the token stream simply ends.
+    Eof,
+}
+
+impl TokenKind {
+    /// Whitespace and comments: carried by the CST but skipped by the parser
+    /// when it looks for the next meaningful token.
+    pub fn is_trivia(self) -> bool {
+        matches!(self, TokenKind::Whitespace | TokenKind::Comment)
+    }
+}
+
+/// A single lexed token: its kind, its exact source text, and its byte span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    pub span: Span,
+}
+
+const KEYWORDS: &[(&str, TokenKind)] = &[
+    ("let", TokenKind::KwLet),
+    ("fn", TokenKind::KwFn),
+    ("if", TokenKind::KwIf),
+    ("else", TokenKind::KwElse),
+    ("return", TokenKind::KwReturn),
+];
+
+/// Lex `text` into a lossless stream of tokens: concatenating every
+/// `token.text` in order reproduces `text` exactly, including whitespace,
+/// comments, and any unrecognized bytes (lexed as `TokenKind::Error`).
+pub fn tokenize(text: &str) -> impl Iterator<Item = Token> + '_ {
+    Lexer { text, rest: text, pos: 0 }
+}
+
+struct Lexer<'a> {
+    text: &'a str,
+    rest: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn bump(&mut self, n: usize) -> &'a str {
+        let (chunk, rest) = self.rest.split_at(n);
+        self.rest = rest;
+        self.pos += n;
+        chunk
+    }
+
+    fn take_while(&mut self, mut pred: impl FnMut(char) -> bool) -> &'a str {
+        let n: usize = self.rest.chars().take_while(|&c| pred(c)).map(char::len_utf8).sum();
+        self.bump(n)
+    }
+}
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        let start = self.pos;
+        let mut chars = self.rest.chars();
+        let c = chars.next()?;
+
+        let kind = if c.is_whitespace() {
+            self.take_while(char::is_whitespace);
+            TokenKind::Whitespace
+        } else if self.rest.starts_with("//") {
+            self.take_while(|c| c != '\n');
+            TokenKind::Comment
+        } else if c.is_ascii_digit() {
+            self.take_while(|c| c.is_ascii_digit() || c == '.');
+            TokenKind::Number
+        } else if c == '_' || c.is_alphabetic() {
+            let text = self.take_while(|c| c == '_' || c.is_alphanumeric());
+            return Some(self.finish(start, keyword_or_ident(text)));
+        } else if c == '"' {
+            self.bump(c.len_utf8());
+            self.take_while(|c| c != '"');
+            if self.rest.starts_with('"') {
+                self.bump(1);
+            }
+            TokenKind::String
+        } else {
+            match c {
+                '+' => { self.bump(1); TokenKind::Plus }
+                '-' => { self.bump(1); TokenKind::Minus }
+                '*' => { self.bump(1); TokenKind::Star }
+                '/' => { self.bump(1); TokenKind::Slash }
+                '(' => { self.bump(1); TokenKind::LParen }
+                ')' => { self.bump(1); TokenKind::RParen }
+                '{' => { self.bump(1); TokenKind::LBrace }
+                '}' => { self.bump(1); TokenKind::RBrace }
+                ',' => { self.bump(1); TokenKind::Comma }
+                ';' => { self.bump(1); TokenKind::Semi }
+                '<' => { self.bump(1); TokenKind::Lt }
+                '>' => { self.bump(1); TokenKind::Gt }
+                '=' => {
+                    self.bump(1);
+                    if self.rest.starts_with('=') {
+                        self.bump(1);
+                        TokenKind::EqEq
+                    } else {
+                        TokenKind::Eq
+                    }
+                }
+                '!' => {
+                    self.bump(1);
+                    if self.rest.starts_with('=') {
+                        self.bump(1);
+                        TokenKind::BangEq
+                    } else {
+                        TokenKind::Bang
+                    }
+                }
+                _ => {
+                    self.bump(c.len_utf8());
+                    TokenKind::Error
+                }
+            }
+        };
+
+        Some(self.finish(start, kind))
+    }
+}
+
+impl<'a> Lexer<'a> {
+    fn finish(&self, start: usize, kind: TokenKind) -> Token {
+        Token { kind, text: self.text[start..self.pos].to_string(), span: Span::new(start, self.pos) }
+    }
+}
+
+fn keyword_or_ident(text: &str) -> TokenKind {
+    KEYWORDS.iter().find(|(kw, _)| *kw == text).map(|(_, k)| *k).unwrap_or(TokenKind::Ident)
+}
+
+/// Whether `text` lexes as a reserved word rather than a plain identifier.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn is_keyword(text: &str) -> bool {
+    KEYWORDS.iter().any(|(kw, _)| *kw == text)
+}
@@ -0,0 +1,18 @@
+//! Lexer and parser for NPL, the toy language exercised by the fuzz target
+//! in `6-NSIGII_Not_A_Toy/fuzz`.
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_gen;
+pub mod dict;
+mod error;
+mod lexer;
+mod parser;
+mod printer;
+mod syntax;
+
+#[cfg(feature = "arbitrary")]
+pub use arbitrary_gen::{arbitrary_source, BinOp, Expr, Ident, Program, Stmt};
+pub use error::{ParseError, Span};
+pub use lexer::{tokenize, Token, TokenKind};
+pub use parser::{parse, parse_recovering, Parse};
+pub use syntax::{NodeKind, SyntaxNode, SyntaxTree};
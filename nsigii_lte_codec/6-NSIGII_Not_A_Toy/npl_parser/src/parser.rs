@@ -0,0 +1,255 @@
+use crate::error::{ParseError, Span};
+use crate::lexer::{tokenize, Token, TokenKind};
+use crate::syntax::{NodeKind, SyntaxNode, SyntaxTree};
+
+/// The result of [`crate::parse_recovering`]: a lossless syntax tree plus
+/// every diagnostic collected along the way, in the style of rust-analyzer's
+/// early `libsyntax2::File` (`.syntax()`, `.ast()`, `.errors()`).
+///
+/// `parse.syntax().text()` always equals the original input byte-for-byte,
+/// regardless of how many errors were recovered from.
+#[derive(Debug, Clone)]
+pub struct Parse {
+    tree: SyntaxTree,
+    errors: Vec<ParseError>,
+}
+
+impl Parse {
+    pub fn syntax(&self) -> &SyntaxNode {
+        self.tree.syntax()
+    }
+
+    /// The parsed tree itself. NPL doesn't (yet) have a separate typed AST
+    /// layer over the CST, so this hands back the same lossless tree as
+    /// [`Parse::syntax`]; callers that want typed accessors should match on
+    /// `SyntaxNode::Node { kind, .. }`.
+    pub fn ast(&self) -> &SyntaxNode {
+        self.tree.syntax()
+    }
+
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+
+    pub fn tree(&self) -> &SyntaxTree {
+        &self.tree
+    }
+}
+
+/// Parses `text`, never bailing out on the first error: the returned tree
+/// always covers 100% of the input (including whitespace and comments), and
+/// every malformed region is recorded as an [`ParseError`] with a byte span
+/// instead of aborting the parse.
+pub fn parse_recovering(text: &str) -> Parse {
+    let tokens: Vec<Token> = tokenize(text).collect();
+    let mut p = Parser { tokens, pos: 0, pending_trivia: Vec::new(), errors: Vec::new() };
+    let root = p.parse_root();
+    Parse { tree: SyntaxTree { root }, errors: p.errors }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    pending_trivia: Vec<SyntaxNode>,
+    errors: Vec<ParseError>,
+}
+
+impl Parser {
+    fn peek_raw(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    /// Advances past any trivia, stashing it so the next node emitted picks
+    /// it up as leading children (keeps the tree lossless).
+    fn skip_trivia(&mut self) {
+        while let Some(tok) = self.peek_raw() {
+            if !tok.kind.is_trivia() {
+                break;
+            }
+            self.pending_trivia.push(SyntaxNode::Token {
+                kind: tok.kind,
+                text: tok.text.clone(),
+                span: tok.span,
+            });
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<TokenKind> {
+        self.skip_trivia();
+        self.peek_raw().map(|t| t.kind)
+    }
+
+    fn bump(&mut self) -> SyntaxNode {
+        self.skip_trivia();
+        let tok = self.tokens[self.pos].clone();
+        self.pos += 1;
+        SyntaxNode::Token { kind: tok.kind, text: tok.text, span: tok.span }
+    }
+
+    fn take_pending_trivia(&mut self) -> Vec<SyntaxNode> {
+        std::mem::take(&mut self.pending_trivia)
+    }
+
+    fn error_at_current(&mut self, message: impl Into<String>) -> SyntaxNode {
+        self.skip_trivia();
+        let mut children = self.take_pending_trivia();
+        let span = self.peek_raw().map(|t| t.span).unwrap_or(Span::new(self.text_len(), self.text_len()));
+        self.errors.push(ParseError::new(message, span));
+        if self.peek_raw().is_some() {
+            children.push(self.bump());
+        }
+        SyntaxNode::Node { kind: NodeKind::Error, children }
+    }
+
+    fn text_len(&self) -> usize {
+        self.tokens.last().map(|t| t.span.end).unwrap_or(0)
+    }
+
+    fn parse_root(&mut self) -> SyntaxNode {
+        let mut children = Vec::new();
+        while self.peek().is_some() {
+            children.push(self.parse_stmt());
+        }
+        children.extend(self.take_pending_trivia());
+        SyntaxNode::Node { kind: NodeKind::Root, children }
+    }
+
+    fn parse_stmt(&mut self) -> SyntaxNode {
+        match self.peek() {
+            Some(TokenKind::KwLet) => self.parse_let_stmt(),
+            Some(_) => self.parse_expr_stmt(),
+            None => self.error_at_current("expected a statement"),
+        }
+    }
+
+    fn parse_let_stmt(&mut self) -> SyntaxNode {
+        let mut children = self.take_pending_trivia();
+        children.push(self.bump()); // `let`
+
+        if self.peek() == Some(TokenKind::Ident) {
+            children.extend(self.take_pending_trivia());
+            children.push(self.bump());
+        } else {
+            children.push(self.error_at_current("expected an identifier after `let`"));
+        }
+
+        if self.peek() == Some(TokenKind::Eq) {
+            children.extend(self.take_pending_trivia());
+            children.push(self.bump());
+        } else {
+            children.push(self.error_at_current("expected `=`"));
+        }
+
+        children.push(self.parse_expr());
+
+        if self.peek() == Some(TokenKind::Semi) {
+            children.extend(self.take_pending_trivia());
+            children.push(self.bump());
+        } else {
+            children.push(self.error_at_current("expected `;`"));
+        }
+
+        SyntaxNode::Node { kind: NodeKind::LetStmt, children }
+    }
+
+    fn parse_expr_stmt(&mut self) -> SyntaxNode {
+        let mut children = vec![self.parse_expr()];
+        if self.peek() == Some(TokenKind::Semi) {
+            children.extend(self.take_pending_trivia());
+            children.push(self.bump());
+        } else {
+            children.push(self.error_at_current("expected `;`"));
+        }
+        SyntaxNode::Node { kind: NodeKind::ExprStmt, children }
+    }
+
+    fn parse_expr(&mut self) -> SyntaxNode {
+        self.parse_bin_expr_at(&[TokenKind::Plus, TokenKind::Minus], Self::parse_term)
+    }
+
+    fn parse_term(&mut self) -> SyntaxNode {
+        self.parse_bin_expr_at(&[TokenKind::Star, TokenKind::Slash], Self::parse_factor)
+    }
+
+    /// Left-associative binary expression: `operand (op operand)*`.
+    fn parse_bin_expr_at(
+        &mut self,
+        ops: &[TokenKind],
+        mut operand: impl FnMut(&mut Self) -> SyntaxNode,
+    ) -> SyntaxNode {
+        let mut lhs = operand(self);
+
+        while self.peek().is_some_and(|k| ops.contains(&k)) {
+            let mut children = vec![lhs];
+            children.extend(self.take_pending_trivia());
+            children.push(self.bump());
+            children.push(operand(self));
+            lhs = SyntaxNode::Node { kind: NodeKind::BinExpr, children };
+        }
+
+        lhs
+    }
+
+    fn parse_factor(&mut self) -> SyntaxNode {
+        match self.peek() {
+            Some(TokenKind::Number) | Some(TokenKind::String) => {
+                let mut children = self.take_pending_trivia();
+                children.push(self.bump());
+                SyntaxNode::Node { kind: NodeKind::Literal, children }
+            }
+            Some(TokenKind::Ident) => {
+                let mut children = self.take_pending_trivia();
+                children.push(self.bump());
+                if self.peek() == Some(TokenKind::LParen) {
+                    children.extend(self.take_pending_trivia());
+                    children.push(self.bump());
+                    while self.peek().is_some() && self.peek() != Some(TokenKind::RParen) {
+                        children.push(self.parse_expr());
+                        if self.peek() == Some(TokenKind::Comma) {
+                            children.extend(self.take_pending_trivia());
+                            children.push(self.bump());
+                        } else {
+                            break;
+                        }
+                    }
+                    if self.peek() == Some(TokenKind::RParen) {
+                        children.extend(self.take_pending_trivia());
+                        children.push(self.bump());
+                    } else {
+                        children.push(self.error_at_current("expected `)`"));
+                    }
+                    return SyntaxNode::Node { kind: NodeKind::CallExpr, children };
+                }
+                SyntaxNode::Node { kind: NodeKind::NameRef, children }
+            }
+            Some(TokenKind::LParen) => {
+                let mut children = self.take_pending_trivia();
+                children.push(self.bump());
+                children.push(self.parse_expr());
+                if self.peek() == Some(TokenKind::RParen) {
+                    children.extend(self.take_pending_trivia());
+                    children.push(self.bump());
+                } else {
+                    children.push(self.error_at_current("expected `)`"));
+                }
+                SyntaxNode::Node { kind: NodeKind::ParenExpr, children }
+            }
+            _ => self.error_at_current("expected an expression"),
+        }
+    }
+}
+
+/// Parses `text`, stopping at the first diagnostic: `Ok` only when the input
+/// is entirely well-formed, `Err` with the first [`ParseError`] otherwise.
+///
+/// For partial input or editor/IDE-style incremental use cases, prefer
+/// [`parse_recovering`], which never fails and instead returns a complete
+/// tree alongside every diagnostic found.
+pub fn parse(text: &str) -> Result<SyntaxTree, ParseError> {
+    let parsed = parse_recovering(text);
+    match parsed.errors.into_iter().next() {
+        Some(err) => Err(err),
+        None => Ok(parsed.tree),
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt;
+
+use crate::syntax::SyntaxTree;
+
+/// Prints a [`SyntaxTree`] back to NPL source.
+///
+/// This is a lossless echo, not a formatter: the tree built by
+/// [`crate::parse`]/[`crate::parse_recovering`] is a CST whose leaves keep
+/// their exact source text, including whitespace and comments (see
+/// `syntax.rs`), so printing is just concatenating those leaves back
+/// together. `to_source(tree) == original_input` for any input that was
+/// parsed, but there is no normalization pass — `to_source` cannot reformat
+/// or canonicalize differently-spaced-but-equivalent input to a common
+/// shape. A real formatter would need a printer driven from `NodeKind`
+/// instead of from raw leaf text.
+impl SyntaxTree {
+    pub fn to_source(&self) -> String {
+        self.text()
+    }
+}
+
+impl fmt::Display for SyntaxTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_source())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_recovering;
+
+    #[test]
+    fn to_source_reproduces_spaced_expression() {
+        let source = "let x = 1 + 2;";
+        let tree = parse_recovering(source).tree().clone();
+        assert_eq!(tree.to_source(), source);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::error::Span;
+use crate::lexer::TokenKind;
+
+/// The kind of an interior (non-leaf) node in the concrete syntax tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    Root,
+    LetStmt,
+    ExprStmt,
+    BinExpr,
+    ParenExpr,
+    CallExpr,
+    Literal,
+    NameRef,
+    Error,
+}
+
+/// A lossless concrete syntax tree node: either an interior `Node` with
+/// children, or a leaf `Token` carrying its exact source text (including
+/// trivia) and byte span. Concatenating the text of every leaf, in order,
+/// reproduces the original source exactly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxNode {
+    Node { kind: NodeKind, children: Vec<SyntaxNode> },
+    Token { kind: TokenKind, text: String, span: Span },
+}
+
+impl SyntaxNode {
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            SyntaxNode::Node { kind, .. } => match kind {
+                NodeKind::Root => "Root",
+                NodeKind::LetStmt => "LetStmt",
+                NodeKind::ExprStmt => "ExprStmt",
+                NodeKind::BinExpr => "BinExpr",
+                NodeKind::ParenExpr => "ParenExpr",
+                NodeKind::CallExpr => "CallExpr",
+                NodeKind::Literal => "Literal",
+                NodeKind::NameRef => "NameRef",
+                NodeKind::Error => "Error",
+            },
+            SyntaxNode::Token { .. } => "Token",
+        }
+    }
+
+    pub fn children(&self) -> &[SyntaxNode] {
+        match self {
+            SyntaxNode::Node { children, .. } => children,
+            SyntaxNode::Token { .. } => &[],
+        }
+    }
+
+    /// The byte span this (sub)tree covers: a token's own span, or the span
+    /// from its first child's start to its last child's end. `None` only for
+    /// an empty node (e.g. a `Root` over empty input), which covers nothing.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            SyntaxNode::Token { span, .. } => Some(*span),
+            SyntaxNode::Node { children, .. } => {
+                let first = children.iter().find_map(SyntaxNode::span)?;
+                let last = children.iter().rev().find_map(SyntaxNode::span)?;
+                Some(Span::new(first.start, last.end))
+            }
+        }
+    }
+
+    /// Reconstructs the exact source text this (sub)tree was parsed from.
+    pub fn text(&self) -> String {
+        let mut buf = String::new();
+        self.write_text(&mut buf);
+        buf
+    }
+
+    fn write_text(&self, buf: &mut String) {
+        match self {
+            SyntaxNode::Node { children, .. } => {
+                for child in children {
+                    child.write_text(buf);
+                }
+            }
+            SyntaxNode::Token { text, .. } => buf.push_str(text),
+        }
+    }
+}
+
+/// The result of parsing: a lossless root node. `tree.text()` always equals
+/// the original input, whether or not parsing found any errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxTree {
+    pub(crate) root: SyntaxNode,
+}
+
+impl SyntaxTree {
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.root
+    }
+
+    pub fn text(&self) -> String {
+        self.root.text()
+    }
+}